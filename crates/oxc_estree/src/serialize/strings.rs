@@ -121,28 +121,28 @@ fn write_str(s: &str, table: &[Escape; 256], buffer: &mut CodeBuffer) {
     buffer.print_ascii_byte(b'"');
 
     let bytes = s.as_bytes();
+    let lone_surrogates = std::ptr::eq(table, &ESCAPE_LONE_SURROGATES);
 
     let mut start = 0;
-    let mut iter = bytes.iter().enumerate();
-    while let Some((index, &byte)) = iter.next() {
+    // Find the next byte which needs escaping, scanning a whole SIMD chunk at a time where
+    // possible. Only ASCII bytes and `0xEF` can ever need escaping, so jumping straight to that
+    // byte (instead of visiting every byte in between) can never land in the middle of a
+    // multi-byte UTF-8 character.
+    let mut index = simd::find_next_special(bytes, start, lone_surrogates);
+    while index < bytes.len() {
+        let byte = bytes[index];
         let escape = table[byte as usize];
-        if escape == Escape::__ {
-            continue;
-        }
+        debug_assert!(escape != Escape::__);
 
         // Handle lone surrogates
-        if table == &ESCAPE_LONE_SURROGATES && escape == Escape::LO {
-            let (_, &next1) = iter.next().unwrap();
-            let (_, &next2) = iter.next().unwrap();
+        if lone_surrogates && escape == Escape::LO {
+            let next1 = bytes[index + 1];
+            let next2 = bytes[index + 2];
             if [next1, next2] == [LOSSY_REPLACEMENT_CHAR_BYTES[1], LOSSY_REPLACEMENT_CHAR_BYTES[2]]
             {
                 // Lossy replacement character (U+FFFD) is used as an escape before lone surrogates,
                 // with the code point as 4 x hex characters after it.
-                let (_, &hex1) = iter.next().unwrap();
-                let (_, &hex2) = iter.next().unwrap();
-                let (_, &hex3) = iter.next().unwrap();
-                let (_, &hex4) = iter.next().unwrap();
-                let hex = [hex1, hex2, hex3, hex4];
+                let hex = [bytes[index + 3], bytes[index + 4], bytes[index + 5], bytes[index + 6]];
 
                 // Print the chunk upto before the lossy replacement character.
                 // SAFETY: 0xEF is always the start of a 3-byte unicode character.
@@ -167,8 +167,13 @@ fn write_str(s: &str, table: &[Escape; 256], buffer: &mut CodeBuffer) {
                 // a UTF-8 character boundary.
                 start = index + 7;
             } else {
-                // Some other unicode character starting with 0xEF. Just continue the loop.
+                // Some other unicode character starting with 0xEF. Just continue the loop,
+                // resuming the search after the 3 bytes of this character.
+                index = simd::find_next_special(bytes, index + 3, lone_surrogates);
+                continue;
             }
+
+            index = simd::find_next_special(bytes, start, lone_surrogates);
             continue;
         }
 
@@ -184,6 +189,7 @@ fn write_str(s: &str, table: &[Escape; 256], buffer: &mut CodeBuffer) {
         write_char_escape(escape, byte, buffer);
 
         start = index + 1;
+        index = simd::find_next_special(bytes, start, lone_surrogates);
     }
 
     if start < bytes.len() {
@@ -217,6 +223,206 @@ fn write_char_escape(escape: Escape, byte: u8, buffer: &mut CodeBuffer) {
     }
 }
 
+/// SIMD-accelerated search for the next byte which needs escaping in JSON.
+///
+/// A byte needs escaping if it's `< 0x20`, `== b'"'`, `== b'\\'`, or (when scanning a string
+/// which may contain lone surrogates) `== 0xEF`, the first byte of the lossy replacement
+/// character. These are exactly the bytes which are non-zero in [`ESCAPE`] / [`ESCAPE_LONE_SURROGATES`].
+///
+/// Only ASCII bytes and `0xEF` can ever match, so continuation bytes (`>= 0x80`, other than
+/// `0xEF`) are never mistaken for a byte which needs escaping, and the caller can safely treat
+/// the returned index as a UTF-8 character boundary.
+mod simd {
+    /// Find index of next byte in `bytes[from..]` which needs escaping.
+    ///
+    /// Returns `bytes.len()` if no such byte is found.
+    #[inline]
+    pub(super) fn find_next_special(bytes: &[u8], from: usize, lone_surrogates: bool) -> usize {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx2") {
+                // SAFETY: Just checked AVX2 is supported on this CPU.
+                return unsafe { x86::find_next_special_avx2(bytes, from, lone_surrogates) };
+            }
+            // SAFETY: SSE2 is part of the x86-64 baseline, so is always supported.
+            unsafe { x86::find_next_special_sse2(bytes, from, lone_surrogates) }
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        {
+            // SAFETY: NEON is part of the aarch64 baseline, so is always supported.
+            unsafe { aarch64::find_next_special_neon(bytes, from, lone_surrogates) }
+        }
+
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        find_next_special_scalar(bytes, from, lone_surrogates)
+    }
+
+    /// Scalar fallback. Also used to handle the tail of `bytes` which is too short to fill
+    /// a whole SIMD chunk.
+    fn find_next_special_scalar(bytes: &[u8], from: usize, lone_surrogates: bool) -> usize {
+        bytes[from..]
+            .iter()
+            .position(|&byte| is_special(byte, lone_surrogates))
+            .map_or(bytes.len(), |offset| from + offset)
+    }
+
+    #[inline]
+    fn is_special(byte: u8, lone_surrogates: bool) -> bool {
+        byte < 0x20 || byte == b'"' || byte == b'\\' || (lone_surrogates && byte == 0xEF)
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    mod x86 {
+        use std::arch::x86_64::{
+            __m128i, __m256i, _mm_cmpeq_epi8, _mm_cmplt_epi8, _mm_loadu_si128, _mm_movemask_epi8,
+            _mm_or_si128, _mm_set1_epi8, _mm_xor_si128, _mm256_cmpeq_epi8, _mm256_cmpgt_epi8,
+            _mm256_loadu_si256, _mm256_movemask_epi8, _mm256_or_si256, _mm256_set1_epi8,
+            _mm256_xor_si256,
+        };
+
+        use super::find_next_special_scalar;
+
+        const SSE2_CHUNK: usize = size_of::<__m128i>();
+        const AVX2_CHUNK: usize = size_of::<__m256i>();
+
+        /// # SAFETY
+        /// CPU must support SSE2 (true of all x86-64 CPUs).
+        #[target_feature(enable = "sse2")]
+        pub(super) unsafe fn find_next_special_sse2(
+            bytes: &[u8],
+            from: usize,
+            lone_surrogates: bool,
+        ) -> usize {
+            // Comparisons below are on signed bytes, so flip the sign bit of both operands
+            // to get the effect of an unsigned comparison.
+            let sign_flip = _mm_set1_epi8(i8::MIN);
+            let space = _mm_xor_si128(_mm_set1_epi8(0x20), sign_flip);
+            let quote = _mm_set1_epi8(b'"' as i8);
+            let backslash = _mm_set1_epi8(b'\\' as i8);
+            let ef = _mm_set1_epi8(0xEFu8 as i8);
+
+            let mut index = from;
+            while index + SSE2_CHUNK <= bytes.len() {
+                // SAFETY: Just checked at least `SSE2_CHUNK` bytes remain from `index`.
+                let chunk = unsafe { _mm_loadu_si128(bytes.as_ptr().add(index).cast()) };
+
+                let is_control = _mm_cmplt_epi8(_mm_xor_si128(chunk, sign_flip), space);
+                let is_quote = _mm_cmpeq_epi8(chunk, quote);
+                let is_backslash = _mm_cmpeq_epi8(chunk, backslash);
+                let mut mask = _mm_or_si128(_mm_or_si128(is_control, is_quote), is_backslash);
+                if lone_surrogates {
+                    mask = _mm_or_si128(mask, _mm_cmpeq_epi8(chunk, ef));
+                }
+
+                let bitmask = _mm_movemask_epi8(mask) as u32;
+                if bitmask != 0 {
+                    return index + bitmask.trailing_zeros() as usize;
+                }
+
+                index += SSE2_CHUNK;
+            }
+
+            find_next_special_scalar(bytes, index, lone_surrogates)
+        }
+
+        /// # SAFETY
+        /// CPU must support AVX2.
+        #[target_feature(enable = "avx2")]
+        pub(super) unsafe fn find_next_special_avx2(
+            bytes: &[u8],
+            from: usize,
+            lone_surrogates: bool,
+        ) -> usize {
+            let sign_flip = _mm256_set1_epi8(i8::MIN);
+            let space = _mm256_xor_si256(_mm256_set1_epi8(0x20), sign_flip);
+            let quote = _mm256_set1_epi8(b'"' as i8);
+            let backslash = _mm256_set1_epi8(b'\\' as i8);
+            let ef = _mm256_set1_epi8(0xEFu8 as i8);
+
+            let mut index = from;
+            while index + AVX2_CHUNK <= bytes.len() {
+                // SAFETY: Just checked at least `AVX2_CHUNK` bytes remain from `index`.
+                let chunk = unsafe { _mm256_loadu_si256(bytes.as_ptr().add(index).cast()) };
+
+                let is_control = _mm256_cmpgt_epi8(space, _mm256_xor_si256(chunk, sign_flip));
+                let is_quote = _mm256_cmpeq_epi8(chunk, quote);
+                let is_backslash = _mm256_cmpeq_epi8(chunk, backslash);
+                let mut mask =
+                    _mm256_or_si256(_mm256_or_si256(is_control, is_quote), is_backslash);
+                if lone_surrogates {
+                    mask = _mm256_or_si256(mask, _mm256_cmpeq_epi8(chunk, ef));
+                }
+
+                let bitmask = _mm256_movemask_epi8(mask) as u32;
+                if bitmask != 0 {
+                    return index + bitmask.trailing_zeros() as usize;
+                }
+
+                index += AVX2_CHUNK;
+            }
+
+            // SAFETY: SSE2 is always available when AVX2 is.
+            unsafe { find_next_special_sse2(bytes, index, lone_surrogates) }
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    mod aarch64 {
+        use std::arch::aarch64::{
+            uint8x16_t, vceqq_u8, vcltq_u8, vdupq_n_u8, vld1q_u8, vmaxvq_u8, vorrq_u8,
+        };
+
+        use super::find_next_special_scalar;
+
+        const NEON_CHUNK: usize = size_of::<uint8x16_t>();
+
+        /// # SAFETY
+        /// CPU must support NEON (true of all aarch64 CPUs).
+        #[target_feature(enable = "neon")]
+        pub(super) unsafe fn find_next_special_neon(
+            bytes: &[u8],
+            from: usize,
+            lone_surrogates: bool,
+        ) -> usize {
+            let space = vdupq_n_u8(0x20);
+            let quote = vdupq_n_u8(b'"');
+            let backslash = vdupq_n_u8(b'\\');
+            let ef = vdupq_n_u8(0xEF);
+
+            let mut index = from;
+            while index + NEON_CHUNK <= bytes.len() {
+                // SAFETY: Just checked at least `NEON_CHUNK` bytes remain from `index`.
+                let chunk = unsafe { vld1q_u8(bytes.as_ptr().add(index)) };
+
+                let is_control = vcltq_u8(chunk, space);
+                let is_quote = vceqq_u8(chunk, quote);
+                let is_backslash = vceqq_u8(chunk, backslash);
+                let mut mask = vorrq_u8(vorrq_u8(is_control, is_quote), is_backslash);
+                if lone_surrogates {
+                    mask = vorrq_u8(mask, vceqq_u8(chunk, ef));
+                }
+
+                // `vmaxvq_u8` is a cheap way to test "is any lane non-zero" without having to
+                // extract a per-lane bitmask (NEON has no direct equivalent of `movemask`).
+                // When it fires, fall back to a scalar scan of just this one 16-byte chunk to
+                // pinpoint the exact byte.
+                if vmaxvq_u8(mask) != 0 {
+                    return find_next_special_scalar(
+                        &bytes[..index + NEON_CHUNK],
+                        index,
+                        lone_surrogates,
+                    );
+                }
+
+                index += NEON_CHUNK;
+            }
+
+            find_next_special_scalar(bytes, index, lone_surrogates)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::CompactTSSerializer;
@@ -243,6 +449,21 @@ mod tests {
                 r#"They call me "Bob" but I prefer "Dennis", innit?"#,
                 r#""They call me \"Bob\" but I prefer \"Dennis\", innit?""#,
             ),
+            // Exactly one 32-byte (AVX2-width) chunk, entirely safe, bulk-copied in one go.
+            (
+                "abcdefghijklmnopqrstuvwxyz012345",
+                r#""abcdefghijklmnopqrstuvwxyz012345""#,
+            ),
+            // Escape on the last byte of the first 32-byte chunk, continuing into a second chunk.
+            (
+                "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\"tail",
+                r#""aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa\"tail""#,
+            ),
+            // Safe run spans more than one chunk before an escape is hit in a later chunk.
+            (
+                "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb\\cccccccccc",
+                r#""bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb\\cccccccccc""#,
+            ),
         ];
 
         for (input, output) in cases {
@@ -286,6 +507,16 @@ mod tests {
             ("_x_\u{FFFD}d834\u{FFFD}d835", r#""_x_\ud834\ud835""#),
             ("\u{FFFD}d834\u{FFFD}d835_y_", r#""\ud834\ud835_y_""#),
             ("_x_\u{FFFD}d834_y_\u{FFFD}d835_z_", r#""_x_\ud834_y_\ud835_z_""#),
+            // Lone-surrogate escape sequence straddling the 32-byte (AVX2-width) chunk boundary.
+            (
+                "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx\u{FFFD}d834_y_",
+                r#""xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx\ud834_y_""#,
+            ),
+            // Lossy replacement character escape sequence straddling a chunk boundary.
+            (
+                "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx\u{FFFD}fffd_y_",
+                "\"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx\u{FFFD}_y_\"",
+            ),
         ];
 
         for (input, output) in cases {